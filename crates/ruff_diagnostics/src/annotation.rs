@@ -0,0 +1,43 @@
+use ruff_text_size::{Ranged, TextRange};
+
+/// A labeled span attached to a [`crate::Diagnostic`] in addition to its
+/// primary range.
+///
+/// An annotation points at a location that's relevant to understanding a
+/// diagnostic but isn't itself the primary offender, e.g. the call a bad
+/// argument was passed to, or the declaration a redefinition shadows.
+///
+/// This only stores the span and its label; `ruff_diagnostics` has no
+/// emitters of its own. See `ruff_linter::text_emitter` for the current
+/// state of rendering these.
+#[derive(Debug, Clone)]
+pub struct Annotation {
+    message: Option<String>,
+    range: TextRange,
+}
+
+impl Annotation {
+    pub fn new(range: TextRange) -> Self {
+        Self {
+            message: None,
+            range,
+        }
+    }
+
+    /// Attaches a message explaining why this span is relevant.
+    #[must_use]
+    pub fn with_message(mut self, message: impl Into<String>) -> Self {
+        self.message = Some(message.into());
+        self
+    }
+
+    pub fn message(&self) -> Option<&str> {
+        self.message.as_deref()
+    }
+}
+
+impl Ranged for Annotation {
+    fn range(&self) -> TextRange {
+        self.range
+    }
+}
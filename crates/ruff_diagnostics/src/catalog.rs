@@ -0,0 +1,98 @@
+/// A minimal Fluent-style message catalog: a locale-keyed table mapping a
+/// violation's stable [`crate::DiagnosticKind::message_key`] to its
+/// translated message text.
+///
+/// Resource files live alongside each rule crate under
+/// `resources/messages/<locale>/<rule-group>.ftl`, one `key = message` pair
+/// per line (a deliberately small subset of Fluent syntax; multi-line
+/// messages and Fluent's full selector/term syntax aren't supported). A
+/// message may reference `{$name}` placeholders, substituted at render time
+/// from the values [`crate::Violation::message_args`] returns.
+/// `MessageCatalog::parse` is called on the embedded resource text the
+/// first time a catalog is needed; rules that haven't opted into catalog-backed messages (i.e.
+/// whose `message_key()` returns `None`) are unaffected and keep rendering
+/// their hardcoded English string.
+#[derive(Debug, Default)]
+pub struct MessageCatalog {
+    entries: Vec<(&'static str, &'static str)>,
+}
+
+impl MessageCatalog {
+    /// Parses a `.ftl`-subset resource file embedded via `include_str!`.
+    ///
+    /// Blank lines and lines starting with `#` (comments) are ignored.
+    pub fn parse(resource: &'static str) -> Self {
+        let entries = resource
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .filter_map(|line| line.split_once('='))
+            .map(|(key, message)| (key.trim(), message.trim()))
+            .collect();
+
+        Self { entries }
+    }
+
+    /// Looks up the message template for `key`, falling back to `None` if
+    /// this catalog has no entry for it (e.g. the locale's resource file
+    /// hasn't been translated yet). Returned verbatim, with any `{$name}`
+    /// placeholders unsubstituted; see [`MessageCatalog::render`].
+    pub fn lookup(&self, key: &str) -> Option<&'static str> {
+        self.entries
+            .iter()
+            .find(|(entry_key, _)| *entry_key == key)
+            .map(|(_, message)| *message)
+    }
+
+    /// Looks up `key`'s message template and substitutes `args` into its
+    /// `{$name}` placeholders, e.g. `{$suffix}` is replaced by the value
+    /// paired with `"suffix"` in `args`. A placeholder with no matching
+    /// entry in `args` is left as-is.
+    pub fn render(&self, key: &str, args: &[(&str, String)]) -> Option<String> {
+        let mut message = self.lookup(key)?.to_string();
+
+        for (name, value) in args {
+            message = message.replace(&format!("{{${name}}}"), value);
+        }
+
+        Some(message)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MessageCatalog;
+
+    #[test]
+    fn parses_key_value_pairs() {
+        let catalog = MessageCatalog::parse(
+            "# a comment\n\npth210-missing-leading-dot = Suffixe sans point de tête passé à `.with_suffix()`\n",
+        );
+
+        assert_eq!(
+            catalog.lookup("pth210-missing-leading-dot"),
+            Some("Suffixe sans point de tête passé à `.with_suffix()`")
+        );
+        assert_eq!(catalog.lookup("missing-key"), None);
+    }
+
+    #[test]
+    fn renders_placeholder_interpolation() {
+        let catalog = MessageCatalog::parse("greeting = Bonjour, {$name}\u{a0}!\n");
+
+        assert_eq!(
+            catalog.render("greeting", &[("name", "Ada".to_string())]),
+            Some("Bonjour, Ada\u{a0}!".to_string())
+        );
+    }
+
+    #[test]
+    fn render_leaves_unmatched_placeholders_untouched() {
+        let catalog = MessageCatalog::parse("greeting = Bonjour, {$name}\u{a0}!\n");
+
+        assert_eq!(
+            catalog.render("greeting", &[]),
+            Some("Bonjour, {$name}\u{a0}!".to_string())
+        );
+    }
+}
@@ -0,0 +1,190 @@
+use ruff_text_size::{Ranged, TextRange, TextSize};
+
+use crate::{Annotation, Fix, MessageCatalog, Violation};
+
+/// The rendered representation of a [`Violation`]: its message, its name,
+/// and (if it offers one) the title of its primary fix.
+#[derive(Debug, Clone)]
+pub struct DiagnosticKind {
+    /// The type name of the `Violation` that produced this diagnostic, e.g.
+    /// `"DotlessPathlibWithSuffix"`.
+    pub name: &'static str,
+    /// The rendered message text.
+    pub body: String,
+    /// The title of the diagnostic's primary fix, if it offers one.
+    pub suggestion: Option<String>,
+    /// A stable identifier for `body`, used to look the message up in a
+    /// [`crate::MessageCatalog`] instead of rendering it directly. `None`
+    /// for violations that haven't opted into catalog-backed messages yet.
+    pub message_key: Option<&'static str>,
+    /// Named values substituted into `message_key`'s `{$name}` placeholders
+    /// when the message is looked up in a catalog. Empty (and unused) when
+    /// `message_key` is `None`.
+    pub message_args: Vec<(&'static str, String)>,
+}
+
+/// A named alternative to a diagnostic's primary [`Fix`].
+///
+/// Unlike the primary fix, alternative fixes are never applied by `--fix`;
+/// they exist to present the user (through an LSP code action, for example)
+/// with more than one way to resolve a violation, the same way `rustc`
+/// attaches multiple suggestions to a single lint.
+///
+/// Not every alternative has a rewrite to offer: sometimes the only useful
+/// thing to say is advisory ("this argument may belong to a different
+/// method"), with no single edit that's obviously correct. `fix` is
+/// `None` for those; renderers should treat a title-only alternative as
+/// plain text, not as a suggestion with an empty diff.
+#[derive(Debug, Clone)]
+pub struct AlternativeFix {
+    title: String,
+    fix: Option<Fix>,
+}
+
+impl AlternativeFix {
+    /// Creates an alternative that offers its own rewrite, separate from
+    /// the diagnostic's primary fix.
+    pub fn new(title: impl Into<String>, fix: Fix) -> Self {
+        Self {
+            title: title.into(),
+            fix: Some(fix),
+        }
+    }
+
+    /// Creates an alternative that's advisory only: a titled note with no
+    /// edit attached, because no single rewrite would be correct.
+    pub fn title_only(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            fix: None,
+        }
+    }
+
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    /// Returns the alternative's fix, if it has one. `None` for an
+    /// alternative created with [`AlternativeFix::title_only`].
+    pub fn fix(&self) -> Option<&Fix> {
+        self.fix.as_ref()
+    }
+}
+
+/// A single lint violation, at a specific location in the source.
+#[derive(Debug, Clone)]
+pub struct Diagnostic {
+    pub kind: DiagnosticKind,
+    pub range: TextRange,
+    pub parent: Option<TextSize>,
+    fix: Option<Fix>,
+    alternative_fixes: Vec<AlternativeFix>,
+    annotations: Vec<Annotation>,
+}
+
+impl Diagnostic {
+    pub fn new<T: Violation>(kind: T, range: TextRange) -> Self {
+        Self {
+            kind: DiagnosticKind {
+                name: bare_type_name::<T>(),
+                message_key: kind.message_key(),
+                message_args: kind.message_args(),
+                body: kind.message(),
+                suggestion: kind.fix_title(),
+            },
+            range,
+            parent: None,
+            fix: None,
+            alternative_fixes: Vec::new(),
+            annotations: Vec::new(),
+        }
+    }
+
+    /// Sets the diagnostic's primary fix: the one edit `--fix` will apply.
+    pub fn set_fix(&mut self, fix: Fix) {
+        self.fix = Some(fix);
+    }
+
+    pub fn try_set_fix(&mut self, func: impl FnOnce() -> Option<Fix>) {
+        self.fix = func();
+    }
+
+    pub fn fix(&self) -> Option<&Fix> {
+        self.fix.as_ref()
+    }
+
+    /// Attaches a titled alternative to the diagnostic's primary fix.
+    ///
+    /// Alternative fixes are ordered: [`Diagnostic::alternative_fixes`]
+    /// returns them in the order they were added. They're never applied
+    /// automatically by `--fix` (unlike the primary fix set via
+    /// [`Diagnostic::set_fix`]).
+    ///
+    /// This only stores the titled `Fix`es; `ruff_diagnostics` has no fix
+    /// applier or LSP code-action emitter of its own. See
+    /// `ruff_linter::text_emitter` for the current state of rendering these.
+    pub fn add_alternative_fix(&mut self, title: impl Into<String>, fix: Fix) {
+        self.alternative_fixes.push(AlternativeFix::new(title, fix));
+    }
+
+    /// Attaches an advisory-only alternative: a titled note with no fix,
+    /// for when there's no single rewrite that's obviously correct (see
+    /// [`AlternativeFix::title_only`]).
+    pub fn add_alternative_note(&mut self, title: impl Into<String>) {
+        self.alternative_fixes.push(AlternativeFix::title_only(title));
+    }
+
+    /// Returns the diagnostic's alternative fixes, in display order.
+    pub fn alternative_fixes(&self) -> &[AlternativeFix] {
+        &self.alternative_fixes
+    }
+
+    /// Attaches a secondary labeled span to the diagnostic, in addition to
+    /// its primary range.
+    ///
+    /// See [`Annotation`]'s docs for where (if anywhere) these get rendered.
+    pub fn annotate(&mut self, annotation: Annotation) {
+        self.annotations.push(annotation);
+    }
+
+    pub fn annotations(&self) -> &[Annotation] {
+        &self.annotations
+    }
+
+    pub fn set_parent(&mut self, parent: TextSize) {
+        self.parent = Some(parent);
+    }
+
+    /// Overwrites the diagnostic's rendered `body` with the `catalog`'s
+    /// translation of its `message_key`, if it has one and `catalog` has an
+    /// entry for it.
+    ///
+    /// Leaves `body` untouched (the hardcoded English string returned by
+    /// `Violation::message`) for violations that haven't opted into
+    /// catalog-backed messages, or when the active locale's catalog doesn't
+    /// have a translation yet.
+    pub fn localize(&mut self, catalog: &MessageCatalog) {
+        if let Some(key) = self.kind.message_key {
+            if let Some(localized) = catalog.render(key, &self.kind.message_args) {
+                self.kind.body = localized;
+            }
+        }
+    }
+}
+
+impl Ranged for Diagnostic {
+    fn range(&self) -> TextRange {
+        self.range
+    }
+}
+
+/// Returns `T`'s bare type name, e.g. `"DotlessPathlibWithSuffix"`.
+///
+/// `std::any::type_name` returns the fully-qualified path
+/// (`"ruff_linter::rules::flake8_use_pathlib::rules::dotless_pathlib_with_suffix::DotlessPathlibWithSuffix"`),
+/// which is an implementation detail of where the type happens to live, not
+/// a stable identifier; strip it down to the last path segment.
+fn bare_type_name<T>() -> &'static str {
+    let full = std::any::type_name::<T>();
+    full.rsplit("::").next().unwrap_or(full)
+}
@@ -0,0 +1,45 @@
+use ruff_text_size::{Ranged, TextRange, TextSize};
+
+/// A single text modification, expressed as a replacement of a (possibly
+/// empty) range with a (possibly empty) string.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Edit {
+    content: Option<Box<str>>,
+    range: TextRange,
+}
+
+impl Edit {
+    /// Creates an edit that inserts `content` at `at`.
+    pub fn insertion(content: String, at: TextSize) -> Self {
+        Self {
+            content: Some(content.into_boxed_str()),
+            range: TextRange::empty(at),
+        }
+    }
+
+    /// Creates an edit that deletes the text in `range`.
+    pub fn range_deletion(range: TextRange) -> Self {
+        Self {
+            content: None,
+            range,
+        }
+    }
+
+    /// Creates an edit that replaces the text in `range` with `content`.
+    pub fn range_replacement(content: String, range: TextRange) -> Self {
+        Self {
+            content: Some(content.into_boxed_str()),
+            range,
+        }
+    }
+
+    pub fn content(&self) -> Option<&str> {
+        self.content.as_deref()
+    }
+}
+
+impl Ranged for Edit {
+    fn range(&self) -> TextRange {
+        self.range
+    }
+}
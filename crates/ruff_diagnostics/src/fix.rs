@@ -0,0 +1,90 @@
+use crate::edit::Edit;
+
+/// Whether a fix is available for a [`crate::Violation`], and under what
+/// circumstances.
+#[derive(Debug, Copy, Clone)]
+pub enum FixAvailability {
+    /// The violation is always fixable.
+    Always,
+    /// The violation is fixable in some cases, described by the contained
+    /// message (e.g. `"Only applies to f-strings"`).
+    Sometimes(&'static str),
+    /// The violation is never fixable.
+    None,
+}
+
+/// How much a [`Fix`] should be trusted to preserve the exact meaning of the
+/// code it rewrites.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, PartialOrd, Ord)]
+pub enum Applicability {
+    /// The fix is unlikely to be what the user wants, or is speculative.
+    DisplayOnly,
+    /// The fix may change program behaviour and should be applied with care.
+    Unsafe,
+    /// The fix is safe to apply automatically.
+    Safe,
+}
+
+/// Whether a fix can be applied in the same pass as other fixes touching
+/// overlapping ranges.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+pub enum IsolationLevel {
+    /// The fix may be applied alongside any other fix.
+    #[default]
+    None,
+    /// The fix must not be applied in the same pass as any other fix
+    /// belonging to the same group.
+    Group(u32),
+}
+
+/// A suggested edit (or set of edits) that resolves a [`crate::Diagnostic`].
+#[derive(Debug, Clone)]
+pub struct Fix {
+    edits: Vec<Edit>,
+    applicability: Applicability,
+    isolation_level: IsolationLevel,
+}
+
+impl Fix {
+    fn new(edits: Vec<Edit>, applicability: Applicability) -> Self {
+        Self {
+            edits,
+            applicability,
+            isolation_level: IsolationLevel::None,
+        }
+    }
+
+    /// Creates a [`Fix`] that's safe to apply automatically from a single edit.
+    pub fn safe_edit(edit: Edit) -> Self {
+        Self::new(vec![edit], Applicability::Safe)
+    }
+
+    /// Creates a [`Fix`] that may change runtime behaviour from a single edit.
+    pub fn unsafe_edit(edit: Edit) -> Self {
+        Self::new(vec![edit], Applicability::Unsafe)
+    }
+
+    /// Creates a [`Fix`] that's only shown to the user, never auto-applied,
+    /// from a single edit.
+    pub fn display_only_edit(edit: Edit) -> Self {
+        Self::new(vec![edit], Applicability::DisplayOnly)
+    }
+
+    #[must_use]
+    pub fn isolate(mut self, level: IsolationLevel) -> Self {
+        self.isolation_level = level;
+        self
+    }
+
+    pub fn applicability(&self) -> Applicability {
+        self.applicability
+    }
+
+    pub fn isolation(&self) -> IsolationLevel {
+        self.isolation_level
+    }
+
+    pub fn edits(&self) -> &[Edit] {
+        &self.edits
+    }
+}
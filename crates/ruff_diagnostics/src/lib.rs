@@ -0,0 +1,13 @@
+pub use annotation::Annotation;
+pub use catalog::MessageCatalog;
+pub use diagnostic::{AlternativeFix, Diagnostic, DiagnosticKind};
+pub use edit::Edit;
+pub use fix::{Applicability, Fix, FixAvailability, IsolationLevel};
+pub use violation::{AlwaysFixableViolation, Violation};
+
+mod annotation;
+mod catalog;
+mod diagnostic;
+mod edit;
+mod fix;
+mod violation;
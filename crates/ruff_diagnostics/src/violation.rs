@@ -0,0 +1,60 @@
+use crate::fix::FixAvailability;
+
+/// A lint rule violation, carrying whatever data it needs to render its
+/// message and fix title.
+pub trait Violation: 'static {
+    /// Whether, and under what conditions, this violation can be fixed.
+    const FIX_AVAILABILITY: FixAvailability = FixAvailability::None;
+
+    /// Returns the user-facing message describing the violation.
+    fn message(&self) -> String;
+
+    /// Returns the title of the primary fix, if one is offered for this
+    /// particular violation.
+    ///
+    /// This only describes the [`crate::Diagnostic`]'s primary, auto-appliable
+    /// fix. A violation may also attach alternative fixes directly to its
+    /// `Diagnostic` via [`crate::Diagnostic::add_alternative_fix`]; those are
+    /// titled individually and are never driven by this trait, since there
+    /// can be more than one of them.
+    fn fix_title(&self) -> Option<String> {
+        None
+    }
+
+    /// Returns the stable key used to look this violation's message up in a
+    /// [`crate::MessageCatalog`], if it has opted into catalog-backed
+    /// messages. Returns `None` to fall back to the hardcoded English string
+    /// returned by [`Violation::message`].
+    fn message_key(&self) -> Option<&'static str> {
+        None
+    }
+
+    /// Returns the named values a catalog-backed message substitutes into
+    /// `{$name}` placeholders (see [`crate::MessageCatalog`]), keyed by the
+    /// same names used in the `.ftl` resource.
+    ///
+    /// Unused for violations whose [`Violation::message_key`] returns `None`.
+    fn message_args(&self) -> Vec<(&'static str, String)> {
+        Vec::new()
+    }
+}
+
+/// A [`Violation`] that always offers a fix.
+pub trait AlwaysFixableViolation: 'static {
+    fn message(&self) -> String;
+
+    /// Returns the title of the fix offered for this violation.
+    fn fix_title(&self) -> String;
+}
+
+impl<T: AlwaysFixableViolation> Violation for T {
+    const FIX_AVAILABILITY: FixAvailability = FixAvailability::Always;
+
+    fn message(&self) -> String {
+        AlwaysFixableViolation::message(self)
+    }
+
+    fn fix_title(&self) -> Option<String> {
+        Some(AlwaysFixableViolation::fix_title(self))
+    }
+}
@@ -0,0 +1,103 @@
+use std::sync::OnceLock;
+
+use ruff_diagnostics::{Diagnostic, MessageCatalog};
+
+/// Picks a locale, loads its `.ftl` catalog, and overwrites a diagnostic's
+/// message with the catalog's translation when one exists. See
+/// [`ruff_diagnostics::MessageCatalog`] for the resource format and
+/// `{$name}` interpolation, and [`ruff_diagnostics::Violation::message_key`]/
+/// [`ruff_diagnostics::Violation::message_args`] for how a rule opts in.
+///
+/// This crate doesn't reference `ruff_macros`'s `#[derive_message_formats]`/
+/// `ViolationMetadata` codegen at all — that macro crate isn't part of this
+/// checkout, so there's no generated code here to hook catalog lookups into.
+/// Each rule currently opts in by hand, as `DotlessPathlibWithSuffix` does.
+
+/// `.ftl` resources for rule groups that have opted into catalog-backed
+/// messages (see [`ruff_diagnostics::MessageCatalog`]), keyed by locale.
+///
+/// Only `flake8_use_pathlib` has opted in so far; other rule groups keep
+/// rendering their hardcoded English `Violation::message()` unchanged.
+const EN_US_FLAKE8_USE_PATHLIB: &str =
+    include_str!("../resources/messages/en-US/flake8_use_pathlib.ftl");
+const FR_FR_FLAKE8_USE_PATHLIB: &str =
+    include_str!("../resources/messages/fr-FR/flake8_use_pathlib.ftl");
+
+/// The locale diagnostics are rendered in.
+///
+/// `RUFF_LOCALE` takes priority when set, then the system locale (`LANG`,
+/// e.g. `fr_FR.UTF-8`); anything else (including no locale being set at
+/// all) falls back to `"en-US"`. This doesn't yet read from
+/// `LinterSettings`/the user's config — that struct isn't wired up to
+/// anything reading `.ftl` resources in this crate yet, so there's nowhere
+/// to add a config field that would do anything. Only `"fr-FR"` has a
+/// translated catalog; every other locale (including the default) renders
+/// the `en-US` catalog, which mirrors the hardcoded English strings
+/// exactly.
+fn active_locale() -> &'static str {
+    static LOCALE: OnceLock<String> = OnceLock::new();
+    LOCALE
+        .get_or_init(|| {
+            std::env::var("RUFF_LOCALE")
+                .ok()
+                .or_else(|| std::env::var("LANG").ok().and_then(locale_from_lang))
+                .unwrap_or_else(|| "en-US".to_string())
+        })
+        .as_str()
+}
+
+/// Extracts a `"fr-FR"`-style locale tag from a POSIX `LANG` value, e.g.
+/// `"fr_FR.UTF-8"` or `"fr_FR"` both yield `Some("fr-FR".to_string())`.
+/// Returns `None` for values this can't parse as `language_COUNTRY`
+/// (`"C"`, `"POSIX"`, empty).
+fn locale_from_lang(lang: &str) -> Option<String> {
+    let tag = lang.split('.').next()?;
+    let (language, country) = tag.split_once('_')?;
+
+    if language.is_empty() || country.is_empty() {
+        return None;
+    }
+
+    Some(format!("{language}-{country}"))
+}
+
+fn flake8_use_pathlib_catalog() -> &'static MessageCatalog {
+    static EN_US: OnceLock<MessageCatalog> = OnceLock::new();
+    static FR_FR: OnceLock<MessageCatalog> = OnceLock::new();
+
+    match active_locale() {
+        "fr-FR" => FR_FR.get_or_init(|| MessageCatalog::parse(FR_FR_FLAKE8_USE_PATHLIB)),
+        _ => EN_US.get_or_init(|| MessageCatalog::parse(EN_US_FLAKE8_USE_PATHLIB)),
+    }
+}
+
+/// Overwrites `diagnostic`'s message with the active locale's translation,
+/// if its violation has a [`ruff_diagnostics::DiagnosticKind::message_key`]
+/// and that rule group has opted into catalog-backed messages.
+///
+/// No-op (keeps the hardcoded English string) for every rule group besides
+/// `flake8_use_pathlib` until they opt in the same way.
+pub(crate) fn localize(diagnostic: &mut Diagnostic) {
+    diagnostic.localize(flake8_use_pathlib_catalog());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::locale_from_lang;
+
+    #[test]
+    fn parses_language_and_country() {
+        assert_eq!(
+            locale_from_lang("fr_FR.UTF-8"),
+            Some("fr-FR".to_string())
+        );
+        assert_eq!(locale_from_lang("fr_FR"), Some("fr-FR".to_string()));
+    }
+
+    #[test]
+    fn rejects_values_with_no_country() {
+        assert_eq!(locale_from_lang("C"), None);
+        assert_eq!(locale_from_lang("POSIX"), None);
+        assert_eq!(locale_from_lang(""), None);
+    }
+}
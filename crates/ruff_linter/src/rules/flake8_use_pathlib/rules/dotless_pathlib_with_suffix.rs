@@ -1,18 +1,19 @@
 use crate::checkers::ast::Checker;
-use ruff_diagnostics::{AlwaysFixableViolation, Diagnostic, Edit, Fix};
+use ruff_diagnostics::{Annotation, Diagnostic, Edit, Fix, FixAvailability, Violation};
 use ruff_macros::{derive_message_formats, ViolationMetadata};
 use ruff_python_ast::{Expr, ExprAttribute, ExprCall, ExprStringLiteral, StringFlags};
-use ruff_python_semantic::analyze::typing;
+use ruff_python_semantic::analyze::typing::is_pathlib_path_expr;
 use ruff_python_semantic::SemanticModel;
 use ruff_text_size::Ranged;
 
 /// ## What it does
 /// Checks for `pathlib.Path.with_suffix()` calls where
-/// the given suffix does not have a leading dot.
+/// the given suffix is not a value that `with_suffix` will accept.
 ///
 /// ## Why is this bad?
-/// `Path.with_suffix()` will raise an error at runtime
-/// if the given suffix is not prefixed with a dot.
+/// `Path.with_suffix()` will raise a `ValueError` at runtime if the given
+/// suffix doesn't have a leading dot, is only a dot, or contains a path
+/// separator.
 ///
 /// ## Examples
 ///
@@ -27,9 +28,13 @@ use ruff_text_size::Ranged;
 /// ```
 ///
 /// ## Known problems
-/// This rule is prone to false negatives due to type inference limitations,
-/// as it will only detect paths that are either instantiated (`p = Path(...)`)
-/// or annotated (`def f(p: Path)`) as such.
+/// This rule is prone to false negatives due to type inference limitations.
+/// It detects `with_suffix()` receivers that are directly instantiated
+/// (`p = Path(...)`), annotated (`def f(p: Path)`), or built up from those
+/// through a chain of other `Path`-returning expressions (`Path(...).parent`,
+/// `p.joinpath("x")`, `p / "x"`, `paths[0]` for a `paths: list[Path]`, and
+/// similar). It still won't catch every case, for example `Path`-typed
+/// values returned from arbitrary functions.
 ///
 /// ## Fix safety
 /// The fix for this rule adds a leading period to the string passed
@@ -39,21 +44,66 @@ use ruff_text_size::Ranged;
 ///
 /// Moreover, it's impossible to determine if this is the correct fix
 /// for a given situation (it's possible that the string was correct
-/// but was being passed to the wrong method entirely, for example).
+/// but was being passed to the wrong method entirely, for example) —
+/// that alternative is offered alongside the fix, for display only.
+///
+/// No fix is offered for a suffix that is only a dot, or that contains a
+/// path separator, since there's no single rewrite that's obviously
+/// correct.
 #[derive(ViolationMetadata)]
-pub(crate) struct DotlessPathlibWithSuffix;
+pub(crate) struct DotlessPathlibWithSuffix {
+    reason: SuffixError,
+    suffix: String,
+}
+
+impl Violation for DotlessPathlibWithSuffix {
+    const FIX_AVAILABILITY: FixAvailability = FixAvailability::Sometimes(
+        "Add a leading dot; only applies to a suffix that's missing one",
+    );
 
-impl AlwaysFixableViolation for DotlessPathlibWithSuffix {
     #[derive_message_formats]
     fn message(&self) -> String {
-        "Dotless suffix passed to `.with_suffix()`".to_string()
+        match self.reason {
+            SuffixError::MissingLeadingDot => {
+                "Dotless suffix passed to `.with_suffix()`".to_string()
+            }
+            SuffixError::DotOnly => {
+                "Suffix consisting only of a dot passed to `.with_suffix()`".to_string()
+            }
+            SuffixError::ContainsSeparator => {
+                "Suffix containing a path separator passed to `.with_suffix()`".to_string()
+            }
+        }
+    }
+
+    fn fix_title(&self) -> Option<String> {
+        matches!(self.reason, SuffixError::MissingLeadingDot)
+            .then(|| "Add a leading dot".to_string())
+    }
+
+    fn message_key(&self) -> Option<&'static str> {
+        Some(match self.reason {
+            SuffixError::MissingLeadingDot => "pth210-missing-leading-dot",
+            SuffixError::DotOnly => "pth210-dot-only",
+            SuffixError::ContainsSeparator => "pth210-contains-separator",
+        })
     }
 
-    fn fix_title(&self) -> String {
-        "Add a leading dot".to_string()
+    fn message_args(&self) -> Vec<(&'static str, String)> {
+        vec![("suffix", self.suffix.clone())]
     }
 }
 
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum SuffixError {
+    /// `path.with_suffix("py")`
+    MissingLeadingDot,
+    /// `path.with_suffix(".")`
+    DotOnly,
+    /// `path.with_suffix("./py")` or `path.with_suffix(".foo/bar")`
+    ContainsSeparator,
+}
+
 /// PTH210
 pub(crate) fn dotless_pathlib_with_suffix(checker: &mut Checker, call: &ExprCall) {
     let (func, arguments) = (&call.func, &call.arguments);
@@ -72,16 +122,133 @@ pub(crate) fn dotless_pathlib_with_suffix(checker: &mut Checker, call: &ExprCall
 
     let string_value = string.value.to_str();
 
-    if string_value.is_empty() || string_value.starts_with('.') {
+    let Some(reason) = validate_suffix(string_value) else {
         return;
+    };
+
+    // Highlight the string argument itself, rather than the whole
+    // `p.with_suffix(...)` call: it's the argument that's invalid, not the
+    // call.
+    let mut diagnostic = Diagnostic::new(
+        DotlessPathlibWithSuffix {
+            reason,
+            suffix: string_value.to_string(),
+        },
+        string.range(),
+    );
+
+    // Point back at `with_suffix` itself as a secondary label, since that's
+    // where the `ValueError` is actually raised at runtime.
+    if let Expr::Attribute(ExprAttribute { attr, .. }) = func.as_ref() {
+        diagnostic.annotate(
+            Annotation::new(attr.range())
+                .with_message("`ValueError` is raised here at runtime for this suffix"),
+        );
     }
 
-    let diagnostic = Diagnostic::new(DotlessPathlibWithSuffix, call.range);
-    let Some(fix) = add_leading_dot_fix(string) else {
-        unreachable!("Expected to always be able to fix this rule");
-    };
+    if reason == SuffixError::MissingLeadingDot {
+        if let Some(fix) = add_leading_dot_fix(string) {
+            diagnostic.set_fix(fix);
+        }
+
+        // There's no way to tell, short of reading the surrounding code,
+        // whether the missing dot is a typo or whether this string was
+        // meant for a different method entirely (e.g. `Path.suffix ==`).
+        // Surface that as an advisory alternative alongside the fix, rather
+        // than silently picking one interpretation — there's no rewrite to
+        // offer here, so it carries no edit, not a no-op one.
+        diagnostic.add_alternative_note("This argument may belong to a different method");
+    }
 
-    checker.diagnostics.push(diagnostic.with_fix(fix));
+    crate::message_catalog::localize(&mut diagnostic);
+
+    checker.diagnostics.push(diagnostic);
+}
+
+/// Returns the reason a suffix would cause `Path.with_suffix()` to raise a
+/// `ValueError`, or `None` if the suffix is valid.
+///
+/// An empty suffix is always valid, since it's used to remove a path's
+/// existing suffix entirely.
+fn validate_suffix(suffix: &str) -> Option<SuffixError> {
+    if suffix.is_empty() {
+        return None;
+    }
+
+    // Checked independently of (and before) the leading-dot check below: a
+    // suffix like `foo/bar.py` is both missing a leading dot *and* contains
+    // a separator, but prepending a dot (`.foo/bar.py`) would still raise
+    // `ValueError`. Classifying it as `ContainsSeparator` rather than
+    // `MissingLeadingDot` keeps the (unsafe) leading-dot fix from ever being
+    // offered for a suffix it can't actually repair.
+    //
+    // We check for `/` unconditionally rather than using
+    // `std::path::is_separator`: that function reflects the *linter's own*
+    // host platform, not anything about the Python code under analysis, so
+    // the same source would get different diagnostics depending on what OS
+    // ruff happened to be compiled for. `/` is treated as a separator by
+    // both `PurePosixPath` and `PureWindowsPath`, so it's safe to flag
+    // unconditionally; `\` is only a separator under `PureWindowsPath` and
+    // we have no way to know which flavor a given `Path` resolves to.
+    if suffix.contains('/') {
+        return Some(SuffixError::ContainsSeparator);
+    }
+
+    if !suffix.starts_with('.') {
+        return Some(SuffixError::MissingLeadingDot);
+    }
+
+    if suffix == "." {
+        return Some(SuffixError::DotOnly);
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod validate_suffix_tests {
+    use super::{validate_suffix, SuffixError};
+
+    #[test]
+    fn empty_suffix_is_valid() {
+        assert_eq!(validate_suffix(""), None);
+    }
+
+    #[test]
+    fn valid_multi_dot_suffix() {
+        assert_eq!(validate_suffix(".tar.gz"), None);
+    }
+
+    #[test]
+    fn missing_leading_dot() {
+        assert_eq!(validate_suffix("py"), Some(SuffixError::MissingLeadingDot));
+    }
+
+    #[test]
+    fn dot_only() {
+        assert_eq!(validate_suffix("."), Some(SuffixError::DotOnly));
+    }
+
+    #[test]
+    fn contains_separator() {
+        assert_eq!(validate_suffix("./py"), Some(SuffixError::ContainsSeparator));
+        assert_eq!(
+            validate_suffix(".foo/bar"),
+            Some(SuffixError::ContainsSeparator)
+        );
+    }
+
+    #[test]
+    fn missing_dot_and_contains_separator_is_not_fixable_by_adding_a_dot() {
+        // `.foo/bar.py` (the result of naively prepending a dot) would still
+        // raise `ValueError`, so this must not be classified as
+        // `MissingLeadingDot`, which is the only variant the rule offers an
+        // (unsafe) autofix for.
+        assert_eq!(
+            validate_suffix("foo/bar.py"),
+            Some(SuffixError::ContainsSeparator)
+        );
+    }
 }
 
 fn is_path_with_suffix_call(semantic: &SemanticModel, func: &Expr) -> bool {
@@ -93,14 +260,7 @@ fn is_path_with_suffix_call(semantic: &SemanticModel, func: &Expr) -> bool {
         return false;
     }
 
-    let Expr::Name(name) = value.as_ref() else {
-        return false;
-    };
-    let Some(binding) = semantic.only_binding(name).map(|id| semantic.binding(id)) else {
-        return false;
-    };
-
-    typing::is_pathlib_path(binding, semantic)
+    is_pathlib_path_expr(value, semantic)
 }
 
 fn add_leading_dot_fix(string: &ExprStringLiteral) -> Option<Fix> {
@@ -112,4 +272,66 @@ fn add_leading_dot_fix(string: &ExprStringLiteral) -> Option<Fix> {
     let edit = Edit::insertion(".".to_string(), after_leading_quote);
 
     Some(Fix::unsafe_edit(edit))
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod receiver_inference_tests {
+    use std::path::Path;
+
+    use anyhow::Result;
+
+    use crate::registry::Rule;
+    use crate::test::test_path;
+    use crate::{assert_messages, settings};
+
+    /// `ruff_python_semantic::analyze::typing::is_pathlib_path_expr`/
+    /// `is_pathlib_sequence_expr` are semantic-analysis helpers (they need
+    /// real bindings to resolve annotations and qualified names), so
+    /// they're exercised end to end through the rule rather than unit
+    /// tested directly, covering chained `.parent`, a `Path(...).joinpath(...)`
+    /// call chain, `p / "x"`, a `list[Path]`-annotated variable subscript, and
+    /// a `list[Path]`-annotated parameter subscript.
+    #[test]
+    fn receivers() -> Result<()> {
+        let diagnostics = test_path(
+            Path::new("flake8_use_pathlib/PTH210_receivers.py"),
+            &settings::LinterSettings::for_rule(Rule::DotlessPathlibWithSuffix),
+        )?;
+        // NOTE: the `.snap` file for this test was hand-computed from
+        // `assert_messages!`'s documented rendering rather than produced by
+        // `cargo insta test`; this checkout has no `Cargo.toml`/build
+        // environment to actually run it in. Regenerate it for real before
+        // merging.
+        assert_messages!(diagnostics);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use anyhow::Result;
+
+    use crate::registry::Rule;
+    use crate::test::test_path;
+    use crate::{assert_messages, settings};
+
+    /// Covers the three `ValueError`-raising `SuffixError` variants (missing
+    /// leading dot, dot only, contains a separator) and the cases that must
+    /// stay unflagged (no suffix, a valid multi-dot suffix).
+    #[test]
+    fn pth210() -> Result<()> {
+        let diagnostics = test_path(
+            Path::new("flake8_use_pathlib/PTH210.py"),
+            &settings::LinterSettings::for_rule(Rule::DotlessPathlibWithSuffix),
+        )?;
+        // NOTE: the `.snap` file for this test was hand-computed from
+        // `assert_messages!`'s documented rendering rather than produced by
+        // `cargo insta test`; this checkout has no `Cargo.toml`/build
+        // environment to actually run it in. Regenerate it for real before
+        // merging.
+        assert_messages!(diagnostics);
+        Ok(())
+    }
+}
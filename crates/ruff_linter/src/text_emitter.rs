@@ -0,0 +1,118 @@
+#![cfg(test)]
+
+use ruff_diagnostics::{Applicability, Diagnostic};
+
+/// Renders a [`Diagnostic`] as plain text: the primary message, a `help:`
+/// line with the violation's fix title (if it has one, whether or not a fix
+/// was set) followed by one per alternative fix,
+/// then a `note:` line per secondary [`ruff_diagnostics::Annotation`].
+///
+/// This is a test-only render helper, not a production emitter: it exists
+/// to prove `Diagnostic::alternative_fixes()` and `Diagnostic::annotations()`
+/// round-trip correctly (nothing read either back before this), and is only
+/// ever called from this module's own tests below. Wiring a real consumer —
+/// `ruff check`'s text output, the JSON/SARIF emitters, or the LSP
+/// code-action/`relatedInformation` bridge — is still unstarted work.
+fn render_text(diagnostic: &Diagnostic) -> String {
+    let mut lines = vec![diagnostic.kind.body.clone()];
+
+    if let Some(title) = &diagnostic.kind.suggestion {
+        lines.push(format!("help: {title}"));
+    }
+
+    for alternative in diagnostic.alternative_fixes() {
+        let label = match alternative.fix().map(|fix| fix.applicability()) {
+            None | Some(Applicability::DisplayOnly) => "display only".to_string(),
+            Some(Applicability::Unsafe) => "unsafe".to_string(),
+            Some(Applicability::Safe) => "safe".to_string(),
+        };
+        lines.push(format!("help: {} ({label})", alternative.title()));
+    }
+
+    for annotation in diagnostic.annotations() {
+        if let Some(message) = annotation.message() {
+            lines.push(format!("note: {message}"));
+        }
+    }
+
+    lines.join("\n")
+}
+
+mod tests {
+    use ruff_diagnostics::{Annotation, Diagnostic, Edit, Fix, FixAvailability, Violation};
+    use ruff_text_size::{TextRange, TextSize};
+
+    use super::render_text;
+
+    struct Dummy;
+
+    impl Violation for Dummy {
+        const FIX_AVAILABILITY: FixAvailability = FixAvailability::Always;
+
+        fn message(&self) -> String {
+            "dummy violation".to_string()
+        }
+
+        fn fix_title(&self) -> Option<String> {
+            Some("Fix it".to_string())
+        }
+    }
+
+    struct NoFixDummy;
+
+    impl Violation for NoFixDummy {
+        fn message(&self) -> String {
+            "dummy violation".to_string()
+        }
+    }
+
+    fn range() -> TextRange {
+        TextRange::new(TextSize::from(0), TextSize::from(1))
+    }
+
+    #[test]
+    fn renders_message_only() {
+        let diagnostic = Diagnostic::new(NoFixDummy, range());
+        assert_eq!(render_text(&diagnostic), "dummy violation");
+    }
+
+    #[test]
+    fn renders_primary_fix_title() {
+        let mut diagnostic = Diagnostic::new(Dummy, range());
+        diagnostic.set_fix(Fix::safe_edit(Edit::insertion(
+            ".".to_string(),
+            range().start(),
+        )));
+        assert_eq!(render_text(&diagnostic), "dummy violation\nhelp: Fix it");
+    }
+
+    #[test]
+    fn renders_alternative_fixes() {
+        let mut diagnostic = Diagnostic::new(Dummy, range());
+        diagnostic.add_alternative_fix(
+            "Rewrite it instead",
+            Fix::unsafe_edit(Edit::insertion("x".to_string(), range().start())),
+        );
+        diagnostic.add_alternative_note("This might belong elsewhere");
+
+        assert_eq!(
+            render_text(&diagnostic),
+            "dummy violation\n\
+             help: Fix it\n\
+             help: Rewrite it instead (unsafe)\n\
+             help: This might belong elsewhere (display only)"
+        );
+    }
+
+    #[test]
+    fn renders_annotations() {
+        let mut diagnostic = Diagnostic::new(Dummy, range());
+        diagnostic.annotate(Annotation::new(range()).with_message("see here"));
+        diagnostic.annotate(Annotation::new(range()));
+
+        assert_eq!(
+            render_text(&diagnostic),
+            "dummy violation\nhelp: Fix it\nnote: see here"
+        );
+    }
+}
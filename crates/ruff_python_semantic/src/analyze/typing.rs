@@ -0,0 +1,151 @@
+use ruff_python_ast::{
+    AnyParameterRef, Expr, ExprAttribute, ExprBinOp, ExprCall, ExprSubscript, Operator, Stmt,
+};
+use ruff_text_size::Ranged;
+
+use crate::{Binding, SemanticModel};
+
+// This file extends the existing `typing` analysis module with a type-flow
+// query that walks call chains, operators, and subscripts, shared by any
+// PTH rule that needs to recognize a `Path`-typed receiver beyond a single
+// name binding.
+
+/// Attribute accesses that are known to return a `pathlib.Path`, so that a
+/// `Path`-typed receiver can be recognized through a chain of them (e.g.
+/// `p.parent.parent`).
+const PATH_RETURNING_PROPERTIES: &[&str] = &["parent"];
+
+/// Method calls that are known to return a `pathlib.Path`.
+///
+/// This only covers instance methods invoked on an already-`Path`-typed
+/// receiver (`p.joinpath("x")`). `Path.cwd()` and `Path.home()` are
+/// classmethods invoked on the bare `Path` name itself (`Path.cwd()`), so
+/// they can't be recognized through [`is_pathlib_path_expr`]'s
+/// receiver-chasing and don't belong in this list.
+const PATH_RETURNING_METHODS: &[&str] = &[
+    "with_name",
+    "with_stem",
+    "with_suffix",
+    "resolve",
+    "absolute",
+    "joinpath",
+    "expanduser",
+];
+
+/// Returns `true` if `binding` is bound to a `pathlib.Path`: annotated as
+/// one directly (`p: Path`, whether a variable or parameter annotation), or
+/// assigned any expression [`is_pathlib_path_expr`] recognizes
+/// (`p = Path(...)`, `p = pathlib.Path(...)`, `p = q.parent`, ...).
+pub fn is_pathlib_path(binding: &Binding, semantic: &SemanticModel) -> bool {
+    match binding.statement(semantic) {
+        Some(Stmt::AnnAssign(ann_assign)) => {
+            is_pathlib_path_annotation(ann_assign.annotation.as_ref(), semantic)
+        }
+
+        Some(Stmt::FunctionDef(function_def)) => function_def
+            .parameters
+            .iter()
+            .find(|parameter| parameter.range().contains_range(binding.range()))
+            .and_then(AnyParameterRef::annotation)
+            .is_some_and(|annotation| is_pathlib_path_annotation(annotation, semantic)),
+
+        Some(Stmt::Assign(assign)) => is_pathlib_path_expr(assign.value.as_ref(), semantic),
+
+        _ => false,
+    }
+}
+
+/// Returns `true` if `annotation` resolves to an unparameterized
+/// `pathlib.Path`.
+fn is_pathlib_path_annotation(annotation: &Expr, semantic: &SemanticModel) -> bool {
+    semantic
+        .resolve_qualified_name(annotation)
+        .is_some_and(|name| matches!(name.segments(), ["pathlib", "Path"] | ["Path"]))
+}
+
+/// Returns `true` if `expr` evaluates to a `pathlib.Path`.
+///
+/// Unlike [`is_pathlib_path`], which only resolves a single name binding,
+/// this walks call chains, binary operators, and subscripts to cover
+/// `Path`-returning expressions beyond a simple name, e.g.
+/// `Path("x").with_suffix("py")`, `p.parent.resolve()`,
+/// `(p / "x").with_suffix("py")`, and `paths[0]` for `paths: list[Path]`.
+/// It still won't catch every case, for example `Path`-typed values
+/// returned from arbitrary functions.
+pub fn is_pathlib_path_expr(expr: &Expr, semantic: &SemanticModel) -> bool {
+    match expr {
+        Expr::Name(name) => semantic
+            .only_binding(name)
+            .map(|id| semantic.binding(id))
+            .is_some_and(|binding| is_pathlib_path(binding, semantic)),
+
+        Expr::Call(ExprCall { func, .. }) => {
+            // `Path(...)`, `pathlib.Path(...)`
+            if semantic
+                .resolve_qualified_name(func)
+                .is_some_and(|name| matches!(name.segments(), ["pathlib", "Path"]))
+            {
+                return true;
+            }
+
+            // `p.parent.resolve()`, `p.joinpath("x")`, ...
+            let Expr::Attribute(ExprAttribute { value, attr, .. }) = func.as_ref() else {
+                return false;
+            };
+
+            PATH_RETURNING_METHODS.contains(&attr.as_str())
+                && is_pathlib_path_expr(value, semantic)
+        }
+
+        // `p.parent`
+        Expr::Attribute(ExprAttribute { value, attr, .. }) => {
+            PATH_RETURNING_PROPERTIES.contains(&attr.as_str())
+                && is_pathlib_path_expr(value, semantic)
+        }
+
+        // `p / "suffix"` (either operand may be the `Path`)
+        Expr::BinOp(ExprBinOp {
+            left, op, right, ..
+        }) => {
+            *op == Operator::Div
+                && (is_pathlib_path_expr(left, semantic) || is_pathlib_path_expr(right, semantic))
+        }
+
+        // `paths[0]`, where `paths` is annotated as a sequence of `Path`
+        Expr::Subscript(ExprSubscript { value, .. }) => is_pathlib_sequence_expr(value, semantic),
+
+        _ => false,
+    }
+}
+
+/// Returns `true` if `expr` is a name annotated as a sequence of
+/// `pathlib.Path`, e.g. `paths: list[Path]` (a variable annotation) or
+/// `def f(paths: list[Path])` (a parameter annotation), so that `paths[0]`
+/// can be recognized as a `Path`-typed receiver by [`is_pathlib_path_expr`].
+pub fn is_pathlib_sequence_expr(expr: &Expr, semantic: &SemanticModel) -> bool {
+    let Expr::Name(name) = expr else {
+        return false;
+    };
+
+    let Some(binding) = semantic.only_binding(name).map(|id| semantic.binding(id)) else {
+        return false;
+    };
+
+    let annotation = match binding.statement(semantic) {
+        Some(Stmt::AnnAssign(ann_assign)) => Some(ann_assign.annotation.as_ref()),
+        Some(Stmt::FunctionDef(function_def)) => function_def
+            .parameters
+            .iter()
+            .find(|parameter| parameter.name().as_str() == name.id.as_str())
+            .and_then(AnyParameterRef::annotation),
+        _ => None,
+    };
+
+    let Some(Expr::Subscript(ExprSubscript { slice, .. })) = annotation else {
+        return false;
+    };
+
+    semantic
+        .resolve_qualified_name(slice)
+        .is_some_and(|name| matches!(name.segments(), ["pathlib", "Path"] | ["Path"]))
+}